@@ -432,9 +432,16 @@ class Tool(ABC):
             if param.required:
                 required.append(param.name)
 
+        description = self.metadata.description
+        if self.metadata.input_examples:
+            # Function-calling schemas have no examples field, so show the argument format inline
+            description += "\n\nExamples:\n" + "\n".join(
+                f"- {json.dumps(example)}" for example in self.metadata.input_examples
+            )
+
         return {
             "name": self.metadata.name,
-            "description": self.metadata.description,
+            "description": description,
             "parameters": {
                 "type": "object",
                 "properties": properties,
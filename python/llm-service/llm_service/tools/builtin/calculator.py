@@ -73,6 +73,11 @@ class CalculatorTool(Tool):
             sandboxed=True,
             dangerous=False,
             cost_per_use=0.0,
+            input_examples=[
+                {"expression": "2 + 2"},
+                {"expression": "2 ** 10"},
+                {"expression": "sqrt(16) * (3 + 1)"},
+            ],
         )
 
     def _get_parameters(self) -> List[ToolParameter]:
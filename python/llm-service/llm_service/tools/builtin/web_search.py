@@ -1168,6 +1168,10 @@ class WebSearchTool(Tool):
             dangerous=False,
             cost_per_use=0.001,  # Approximate cost per search
             session_aware=True,  # Enable session context for official_domains auto-fetch
+            input_examples=[
+                # Short keyword queries work better than full questions
+                {"query": "rust tokio semaphore example", "max_results": 3},
+            ],
         )
 
     def _get_parameters(self) -> List[ToolParameter]:
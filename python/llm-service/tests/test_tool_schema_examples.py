@@ -0,0 +1,48 @@
+"""
+Test that ToolMetadata.input_examples are surfaced in the function-calling schema.
+"""
+
+import json
+
+from llm_service.tools.base import Tool, ToolMetadata
+from llm_service.tools.builtin.calculator import CalculatorTool
+
+
+class _NoExamplesTool(Tool):
+    def _get_metadata(self) -> ToolMetadata:
+        return ToolMetadata(
+            name="no_examples",
+            version="1.0.0",
+            description="A tool without examples",
+            category="test",
+        )
+
+    def _get_parameters(self):
+        return []
+
+    async def _execute_impl(self, session_context=None, **kwargs):
+        raise NotImplementedError
+
+
+def test_examples_appended_to_description():
+    tool = CalculatorTool()
+    schema = tool.get_schema()
+
+    description = schema["description"]
+    assert description.startswith(tool.metadata.description)
+    assert "Examples:" in description
+    for example in tool.metadata.input_examples:
+        assert json.dumps(example) in description
+
+
+def test_examples_use_declared_parameters():
+    tool = CalculatorTool()
+    params = set(tool.get_schema()["parameters"]["properties"])
+
+    for example in tool.metadata.input_examples:
+        assert set(example) <= params
+
+
+def test_description_unchanged_without_examples():
+    tool = _NoExamplesTool()
+    assert tool.get_schema()["description"] == "A tool without examples"
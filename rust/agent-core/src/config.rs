@@ -240,7 +240,7 @@ pub struct ToolConfig {
     #[serde(default = "default_true")]
     pub enable_caching: bool,
 
-    /// Cache TTL in seconds (default: 300s)
+    /// Cache TTL in seconds, caps per-tool registry TTLs (default: 300s)
     #[serde(default = "default_tool_cache_ttl")]
     pub cache_ttl_secs: u64,
 }
@@ -7,6 +7,8 @@ use tracing::{debug, error, info, warn};
 use crate::enforcement::RequestEnforcer;
 use crate::llm_client::LLMClient;
 use crate::memory::MemoryPool;
+use crate::tool_cache::{ToolCache, DEFAULT_MAX_ENTRIES};
+use crate::tool_registry::ToolRegistry;
 
 #[cfg(feature = "wasi")]
 use crate::wasi_sandbox::WasiSandbox;
@@ -52,6 +54,9 @@ pub struct AgentServiceImpl {
     start_time: std::time::Instant,
     llm: std::sync::Arc<LLMClient>,
     enforcer: std::sync::Arc<RequestEnforcer>,
+    // Shared tool result cache (None when caching is disabled in config)
+    tool_cache: Option<std::sync::Arc<ToolCache>>,
+    tool_registry: std::sync::Arc<ToolRegistry>,
 }
 
 impl Default for AgentServiceImpl {
@@ -78,6 +83,16 @@ impl AgentServiceImpl {
             .parse()
             .unwrap_or(10000);
 
+        let tool_registry = std::sync::Arc::new(ToolRegistry::new());
+        let tools_cfg = crate::config::Config::global()?.tools;
+        let tool_cache = if tools_cfg.enable_caching {
+            let cache = ToolCache::new(DEFAULT_MAX_ENTRIES, tools_cfg.cache_ttl_secs)
+                .with_registry_ttls(&tool_registry);
+            Some(std::sync::Arc::new(cache))
+        } else {
+            None
+        };
+
         Ok(Self {
             memory_pool: MemoryPool::new(512).start_sweeper(sweep_interval_ms), // 512MB memory pool with sweeper
             #[cfg(feature = "wasi")]
@@ -85,6 +100,8 @@ impl AgentServiceImpl {
             start_time: std::time::Instant::now(),
             llm: std::sync::Arc::new(LLMClient::new(None)?),
             enforcer: std::sync::Arc::new(RequestEnforcer::from_global()?),
+            tool_cache,
+            tool_registry,
         })
     }
 
@@ -230,12 +247,12 @@ impl AgentServiceImpl {
         #[cfg(feature = "wasi")]
         let tool_executor = {
             let sandbox = sandbox_override.unwrap_or_else(|| self.sandbox.clone());
-            ToolExecutor::new_with_wasi(Some(sandbox), None)
+            ToolExecutor::new_with_wasi(Some(sandbox), None).with_cache(self.tool_cache.clone())
         };
         #[cfg(not(feature = "wasi"))]
         let tool_executor = {
             let _ = sandbox_override; // Suppress unused warning
-            ToolExecutor::new_with_wasi(None, None)
+            ToolExecutor::new_with_wasi(None, None).with_cache(self.tool_cache.clone())
         };
 
         // Build context with session_id for Firecracker (defense-in-depth: try multiple sources)
@@ -460,9 +477,11 @@ impl AgentServiceImpl {
         let _ = sandbox_override; // Suppress unused warning
 
         #[cfg(feature = "wasi")]
-        let tool_executor = ToolExecutor::new_with_wasi(Some(effective_sandbox.clone()), None);
+        let tool_executor = ToolExecutor::new_with_wasi(Some(effective_sandbox.clone()), None)
+            .with_cache(self.tool_cache.clone());
         #[cfg(not(feature = "wasi"))]
-        let tool_executor = ToolExecutor::new_with_wasi(None, None);
+        let tool_executor =
+            ToolExecutor::new_with_wasi(None, None).with_cache(self.tool_cache.clone());
         let mut tool_calls_vec = Vec::new();
         let mut tool_results_vec = Vec::new();
         let mut overall_status = proto::common::StatusCode::Ok.into();
@@ -573,103 +592,115 @@ impl AgentServiceImpl {
                 dur_ms: i64,
             }
 
+            async fn join_item(jh: tokio::task::JoinHandle<ItemRes>) -> ItemRes {
+                match jh.await {
+                    Ok(item) => item,
+                    Err(e) => ItemRes {
+                        tool_name: "unknown".to_string(),
+                        params_map: HashMap::new(),
+                        success: false,
+                        output: serde_json::Value::Null,
+                        error: format!("join error: {}", e),
+                        dur_ms: 0,
+                    },
+                }
+            }
+
+            // Keep call order: runs of parallel-safe tools fan out, stateful tools act as barriers
+            let batches = self
+                .tool_registry
+                .execution_batches(parsed, |(_, name, _)| name.as_str());
+
             // Pre-allocate results without requiring Clone on ItemRes
             let mut results: Vec<Option<ItemRes>> = (0..total).map(|_| None).collect();
             let wall_start = std::time::Instant::now();
             let mut handles = Vec::with_capacity(total);
-            for (idx, tool_name, params_map) in parsed.into_iter() {
-                let permit = semaphore.clone().acquire_owned().await.map_err(|e| {
-                    tonic::Status::internal(format!("Failed to acquire semaphore permit: {}", e))
-                })?;
-                #[cfg(feature = "wasi")]
-                let sandbox = effective_sandbox.clone();
-                #[cfg(not(feature = "wasi"))]
-                let sandbox = ();
-                let tool_name_c = tool_name.clone();
-                let params_map_c = params_map.clone();
-                // Build context with session_id for Firecracker (defense-in-depth: try multiple sources)
-                let context_c = {
-                    let mut ctx = req.context.clone().unwrap_or_default();
-
-                    // Try to get session_id from multiple sources (priority order)
-                    let session_id = if let Some(session_ctx) = &req.session_context {
-                        if !session_ctx.session_id.is_empty() {
-                            Some(session_ctx.session_id.clone())
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                    .or_else(|| {
-                        // Fallback: try metadata.session_id
-                        req.metadata.as_ref().and_then(|m| {
-                            if !m.session_id.is_empty() {
-                                Some(m.session_id.clone())
+            for batch in batches {
+                for (idx, tool_name, params_map) in batch {
+                    let permit = semaphore.clone().acquire_owned().await.map_err(|e| {
+                        tonic::Status::internal(format!(
+                            "Failed to acquire semaphore permit: {}",
+                            e
+                        ))
+                    })?;
+                    #[cfg(feature = "wasi")]
+                    let sandbox = effective_sandbox.clone();
+                    #[cfg(not(feature = "wasi"))]
+                    let sandbox = ();
+                    let tool_cache = self.tool_cache.clone();
+                    let tool_name_c = tool_name.clone();
+                    let params_map_c = params_map.clone();
+                    // Build context with session_id for Firecracker (defense-in-depth: try multiple sources)
+                    let context_c = {
+                        let mut ctx = req.context.clone().unwrap_or_default();
+
+                        // Try to get session_id from multiple sources (priority order)
+                        let session_id = if let Some(session_ctx) = &req.session_context {
+                            if !session_ctx.session_id.is_empty() {
+                                Some(session_ctx.session_id.clone())
                             } else {
                                 None
                             }
-                        })
-                    });
+                        } else {
+                            None
+                        }
+                        .or_else(|| {
+                            // Fallback: try metadata.session_id
+                            req.metadata.as_ref().and_then(|m| {
+                                if !m.session_id.is_empty() {
+                                    Some(m.session_id.clone())
+                                } else {
+                                    None
+                                }
+                            })
+                        });
 
-                    if let Some(sid) = session_id {
-                        ctx.fields.insert(
-                            "session_id".to_string(),
-                            prost_types::Value {
-                                kind: Some(prost_types::value::Kind::StringValue(sid)),
-                            },
-                        );
-                    }
-                    Some(ctx)
-                };
-                let jh = tokio::spawn(async move {
-                    let _p = permit;
-                    let exec = ToolExecutor::new_with_wasi(Some(sandbox), None);
-                    let call = ToolCall {
-                        tool_name: tool_name_c.clone(),
-                        parameters: params_map_c.clone(),
-                        call_id: None,
+                        if let Some(sid) = session_id {
+                            ctx.fields.insert(
+                                "session_id".to_string(),
+                                prost_types::Value {
+                                    kind: Some(prost_types::value::Kind::StringValue(sid)),
+                                },
+                            );
+                        }
+                        Some(ctx)
                     };
-                    let start = std::time::Instant::now();
-                    let outcome = exec.execute_tool(&call, context_c.as_ref()).await;
-                    let dur_ms = start.elapsed().as_millis() as i64;
-                    match outcome {
-                        Ok(res) => ItemRes {
-                            tool_name: tool_name_c,
-                            params_map: params_map_c,
-                            success: res.success,
-                            output: res.output,
-                            error: res.error.unwrap_or_default(),
-                            dur_ms,
-                        },
-                        Err(e) => ItemRes {
-                            tool_name: tool_name_c,
-                            params_map: params_map_c,
-                            success: false,
-                            output: serde_json::Value::Null,
-                            error: e.to_string(),
-                            dur_ms: 0,
-                        },
-                    }
-                });
-                handles.push((idx, jh));
-            }
-
-            for (idx, jh) in handles {
-                match jh.await {
-                    Ok(item) => {
-                        results[idx] = Some(item);
-                    }
-                    Err(e) => {
-                        results[idx] = Some(ItemRes {
-                            tool_name: "unknown".to_string(),
-                            params_map: HashMap::new(),
-                            success: false,
-                            output: serde_json::Value::Null,
-                            error: format!("join error: {}", e),
-                            dur_ms: 0,
-                        });
-                    }
+                    let jh = tokio::spawn(async move {
+                        let _p = permit;
+                        let exec =
+                            ToolExecutor::new_with_wasi(Some(sandbox), None).with_cache(tool_cache);
+                        let call = ToolCall {
+                            tool_name: tool_name_c.clone(),
+                            parameters: params_map_c.clone(),
+                            call_id: None,
+                        };
+                        let start = std::time::Instant::now();
+                        let outcome = exec.execute_tool(&call, context_c.as_ref()).await;
+                        let dur_ms = start.elapsed().as_millis() as i64;
+                        match outcome {
+                            Ok(res) => ItemRes {
+                                tool_name: tool_name_c,
+                                params_map: params_map_c,
+                                success: res.success,
+                                output: res.output,
+                                error: res.error.unwrap_or_default(),
+                                dur_ms,
+                            },
+                            Err(e) => ItemRes {
+                                tool_name: tool_name_c,
+                                params_map: params_map_c,
+                                success: false,
+                                output: serde_json::Value::Null,
+                                error: e.to_string(),
+                                dur_ms: 0,
+                            },
+                        }
+                    });
+                    handles.push((idx, jh));
+                }
+                // Barrier: the whole batch completes before the next one starts
+                for (idx, jh) in handles.drain(..) {
+                    results[idx] = Some(join_item(jh).await);
                 }
             }
 
@@ -1758,12 +1789,27 @@ impl AgentService for AgentServiceImpl {
 
     async fn discover_tools(
         &self,
-        _request: Request<DiscoverToolsRequest>,
+        request: Request<DiscoverToolsRequest>,
     ) -> Result<Response<DiscoverToolsResponse>, Status> {
-        debug!("Tool discovery requested");
+        let req = request.into_inner();
+        debug!("Tool discovery requested: query='{}'", req.query);
+
+        // Proto3 has no presence for scalars/lists; treat empty or zero as "no filter"
+        let discovery = crate::tool_registry::ToolDiscoveryRequest {
+            query: Some(req.query).filter(|q| !q.is_empty()),
+            categories: Some(req.categories).filter(|c| !c.is_empty()),
+            tags: Some(req.tags).filter(|t| !t.is_empty()),
+            exclude_dangerous: Some(req.exclude_dangerous),
+            max_results: Some(req.max_results).filter(|&m| m > 0).map(|m| m as usize),
+        };
 
         let response = DiscoverToolsResponse {
-            tools: vec![], // Stub implementation
+            tools: self
+                .tool_registry
+                .discover_tools(discovery)
+                .iter()
+                .map(tool_capability_to_proto)
+                .collect(),
         };
 
         Ok(Response::new(response))
@@ -1771,18 +1817,57 @@ impl AgentService for AgentServiceImpl {
 
     async fn get_tool_capability(
         &self,
-        _request: Request<GetToolCapabilityRequest>,
+        request: Request<GetToolCapabilityRequest>,
     ) -> Result<Response<GetToolCapabilityResponse>, Status> {
-        debug!("Tool capability requested");
+        let tool_id = request.into_inner().tool_id;
+        debug!("Tool capability requested: {}", tool_id);
+
+        let tool = self
+            .tool_registry
+            .get_tool(&tool_id)
+            .ok_or_else(|| Status::not_found(format!("Unknown tool: {}", tool_id)))?;
 
         let response = GetToolCapabilityResponse {
-            tool: None, // Stub implementation
+            tool: Some(tool_capability_to_proto(&tool)),
         };
 
         Ok(Response::new(response))
     }
 }
 
+// Helper: convert registry tool metadata (including usage examples) to the proto message
+fn tool_capability_to_proto(tool: &crate::tool_registry::ToolCapability) -> ToolCapability {
+    let to_struct = |v: &serde_json::Value| serde_json_to_prost_struct(&Some(v.clone()));
+    ToolCapability {
+        id: tool.id.clone(),
+        name: tool.name.clone(),
+        description: tool.description.clone(),
+        category: tool.category.clone(),
+        input_schema: to_struct(&tool.input_schema),
+        output_schema: to_struct(&tool.output_schema),
+        required_permissions: tool.required_permissions.clone(),
+        estimated_duration_ms: tool.estimated_duration_ms as i64,
+        is_dangerous: tool.is_dangerous,
+        version: tool.version.clone(),
+        author: tool.author.clone(),
+        tags: tool.tags.clone(),
+        examples: tool
+            .examples
+            .iter()
+            .map(|ex| ToolExample {
+                description: ex.description.clone(),
+                input: to_struct(&ex.input),
+                output: to_struct(&ex.output),
+            })
+            .collect(),
+        rate_limit: tool.rate_limit.as_ref().map(|rl| RateLimit {
+            requests_per_minute: rl.requests_per_minute as i32,
+            requests_per_hour: rl.requests_per_hour as i32,
+        }),
+        cache_ttl_ms: tool.cache_ttl_ms.unwrap_or(0) as i64,
+    }
+}
+
 // Helper: convert prost_types::Value to serde_json::Value for passing context to Python
 fn tool_meta_to_proto(
     meta: &Option<serde_json::Value>,
@@ -25,6 +25,10 @@ pub static TOOL_EXECUTIONS: OnceLock<CounterVec> = OnceLock::new();
 pub static TOOL_DURATION: OnceLock<HistogramVec> = OnceLock::new();
 pub static TOOL_SELECTION_DURATION: OnceLock<HistogramVec> = OnceLock::new();
 
+// Tool cache metrics
+pub static TOOL_CACHE_LOOKUPS: OnceLock<CounterVec> = OnceLock::new(); // labels: tool_name, outcome
+pub static TOOL_CACHE_EVICTIONS: OnceLock<CounterVec> = OnceLock::new(); // labels: reason
+
 // gRPC metrics
 pub static GRPC_REQUESTS: OnceLock<CounterVec> = OnceLock::new();
 pub static GRPC_REQUEST_DURATION: OnceLock<HistogramVec> = OnceLock::new();
@@ -168,6 +172,21 @@ fn init_metrics_internal() -> Result<()> {
     )
     .context("Failed to register TOOL_SELECTION_DURATION metric")?;
 
+    // Tool cache metrics
+    let tool_cache_lookups = register_counter_vec!(
+        "agent_core_tool_cache_lookups_total",
+        "Tool result cache lookups",
+        &["tool_name", "outcome"]
+    )
+    .context("Failed to register TOOL_CACHE_LOOKUPS metric")?;
+
+    let tool_cache_evictions = register_counter_vec!(
+        "agent_core_tool_cache_evictions_total",
+        "Tool result cache evictions",
+        &["reason"]
+    )
+    .context("Failed to register TOOL_CACHE_EVICTIONS metric")?;
+
     // gRPC metrics
     let grpc_requests = register_counter_vec!(
         "agent_core_grpc_requests_total",
@@ -229,6 +248,12 @@ fn init_metrics_internal() -> Result<()> {
     TOOL_SELECTION_DURATION
         .set(tool_selection_duration)
         .map_err(|_| anyhow::anyhow!("Failed to set TOOL_SELECTION_DURATION"))?;
+    TOOL_CACHE_LOOKUPS
+        .set(tool_cache_lookups)
+        .map_err(|_| anyhow::anyhow!("Failed to set TOOL_CACHE_LOOKUPS"))?;
+    TOOL_CACHE_EVICTIONS
+        .set(tool_cache_evictions)
+        .map_err(|_| anyhow::anyhow!("Failed to set TOOL_CACHE_EVICTIONS"))?;
     GRPC_REQUESTS
         .set(grpc_requests)
         .map_err(|_| anyhow::anyhow!("Failed to set GRPC_REQUESTS"))?;
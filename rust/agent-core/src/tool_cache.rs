@@ -1,32 +1,48 @@
+use crate::metrics::{TOOL_CACHE_EVICTIONS, TOOL_CACHE_LOOKUPS};
+use crate::tool_registry::ToolRegistry;
 use crate::tools::{ToolCall, ToolResult};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::sync::{Arc, RwLock};
 use std::time::{Duration, Instant};
 use tracing::{debug, info, instrument, warn};
 
+/// Default maximum number of cached tool results
+pub const DEFAULT_MAX_ENTRIES: usize = 1000;
+
 /// Cache key for tool results
 #[derive(Debug, Clone, Hash, Eq, PartialEq)]
 struct CacheKey {
     tool_name: String,
     parameters_hash: u64,
+    // Hash of the session context the result was produced under, if any
+    context_hash: Option<u64>,
 }
 
 impl CacheKey {
-    fn from_tool_call(call: &ToolCall) -> Self {
+    fn from_tool_call(call: &ToolCall, context: Option<&prost_types::Struct>) -> Self {
         use std::collections::hash_map::DefaultHasher;
         use std::hash::{Hash, Hasher};
 
         let mut hasher = DefaultHasher::new();
 
-        // Hash the parameters in a deterministic way
-        let params_string =
-            serde_json::to_string(&call.parameters).unwrap_or_else(|_| String::new());
+        // Hash the parameters in a deterministic way: HashMap iteration order is
+        // random, so serialize through a key-sorted view
+        let sorted: BTreeMap<&String, &serde_json::Value> = call.parameters.iter().collect();
+        let params_string = serde_json::to_string(&sorted).unwrap_or_else(|_| String::new());
         params_string.hash(&mut hasher);
 
+        // Struct fields are a BTreeMap, so the protobuf encoding is deterministic
+        let context_hash = context.map(|ctx| {
+            let mut hasher = DefaultHasher::new();
+            prost::Message::encode_to_vec(ctx).hash(&mut hasher);
+            hasher.finish()
+        });
+
         Self {
             tool_name: call.tool_name.clone(),
             parameters_hash: hasher.finish(),
+            context_hash,
         }
     }
 }
@@ -73,6 +89,8 @@ pub struct ToolCache {
     stats: Arc<RwLock<CacheStats>>,
     max_size: usize,
     default_ttl: Duration,
+    // Per-tool TTL overrides; only tools listed here are cacheable by the executor
+    tool_ttls: Arc<RwLock<HashMap<String, Duration>>>,
 }
 
 impl ToolCache {
@@ -82,13 +100,47 @@ impl ToolCache {
             stats: Arc::new(RwLock::new(CacheStats::default())),
             max_size,
             default_ttl: Duration::from_secs(default_ttl_seconds),
+            tool_ttls: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Seed per-tool TTLs from the `cache_ttl_ms` of each registered capability
+    pub fn with_registry_ttls(self, registry: &ToolRegistry) -> Self {
+        for tool in registry.list_all_tools() {
+            if let Some(ttl_ms) = tool.cache_ttl_ms {
+                self.set_tool_ttl(&tool.id, Duration::from_millis(ttl_ms));
+            }
         }
+        self
+    }
+
+    /// Set the TTL used when caching results of a specific tool
+    pub fn set_tool_ttl(&self, tool_name: &str, ttl: Duration) {
+        let mut ttls = self.tool_ttls.write().unwrap();
+        ttls.insert(tool_name.to_string(), ttl);
+    }
+
+    /// Get the TTL for a tool, if the tool is cacheable.
+    /// Per-tool TTLs are capped at the cache-wide default TTL.
+    pub fn tool_ttl(&self, tool_name: &str) -> Option<Duration> {
+        let ttls = self.tool_ttls.read().unwrap();
+        ttls.get(tool_name).map(|ttl| (*ttl).min(self.default_ttl))
     }
 
     /// Get a cached result if available and not expired
-    #[instrument(skip(self, call), fields(tool = %call.tool_name))]
     pub fn get(&self, call: &ToolCall) -> Option<ToolResult> {
-        let key = CacheKey::from_tool_call(call);
+        self.get_with_context(call, None)
+    }
+
+    /// Get a cached result produced under the same session context.
+    /// Results never cross sessions: a different (or missing) context is a miss.
+    #[instrument(skip(self, call, context), fields(tool = %call.tool_name))]
+    pub fn get_with_context(
+        &self,
+        call: &ToolCall,
+        context: Option<&prost_types::Struct>,
+    ) -> Option<ToolResult> {
+        let key = CacheKey::from_tool_call(call, context);
 
         let mut cache = self.cache.write().unwrap();
         let mut stats = self.stats.write().unwrap();
@@ -100,6 +152,7 @@ impl ToolCache {
                 cached.hit_count += 1;
                 cached.last_accessed = Instant::now(); // Update for LRU
                 stats.cache_hits += 1;
+                record_lookup(&call.tool_name, "hit");
                 debug!(
                     "Cache hit for tool '{}' (hits: {}, age: {:?})",
                     call.tool_name,
@@ -112,24 +165,37 @@ impl ToolCache {
                 debug!("Cache entry expired for tool '{}'", call.tool_name);
                 cache.remove(&key);
                 stats.evictions += 1;
+                record_eviction("expired");
             }
         }
 
         stats.cache_misses += 1;
+        record_lookup(&call.tool_name, "miss");
         debug!("Cache miss for tool '{}'", call.tool_name);
         None
     }
 
     /// Store a tool result in the cache
-    #[instrument(skip(self, call, result), fields(tool = %call.tool_name))]
     pub fn put(&self, call: &ToolCall, result: ToolResult, ttl_override: Option<Duration>) {
+        self.put_with_context(call, None, result, ttl_override)
+    }
+
+    /// Store a tool result scoped to the session context it was produced under
+    #[instrument(skip(self, call, context, result), fields(tool = %call.tool_name))]
+    pub fn put_with_context(
+        &self,
+        call: &ToolCall,
+        context: Option<&prost_types::Struct>,
+        result: ToolResult,
+        ttl_override: Option<Duration>,
+    ) {
         // Don't cache failed results
         if !result.success {
             debug!("Not caching failed result for tool '{}'", call.tool_name);
             return;
         }
 
-        let key = CacheKey::from_tool_call(call);
+        let key = CacheKey::from_tool_call(call, context);
         let ttl = ttl_override.unwrap_or(self.default_ttl);
 
         let mut cache = self.cache.write().unwrap();
@@ -169,6 +235,7 @@ impl ToolCache {
         {
             cache.remove(&lru_key);
             stats.evictions += 1;
+            record_eviction("capacity");
             debug!("Evicted LRU cache entry for tool '{}'", lru_key.tool_name);
         }
     }
@@ -223,6 +290,7 @@ impl ToolCache {
         for key in &expired_keys {
             cache.remove(key);
             stats.evictions += 1;
+            record_eviction("expired");
         }
 
         if !expired_keys.is_empty() {
@@ -231,9 +299,22 @@ impl ToolCache {
     }
 }
 
+// Mirror cache stats into Prometheus so they reach the metrics endpoint
+fn record_lookup(tool_name: &str, outcome: &str) {
+    if let Some(lookups) = TOOL_CACHE_LOOKUPS.get() {
+        lookups.with_label_values(&[tool_name, outcome]).inc();
+    }
+}
+
+fn record_eviction(reason: &str) {
+    if let Some(evictions) = TOOL_CACHE_EVICTIONS.get() {
+        evictions.with_label_values(&[reason]).inc();
+    }
+}
+
 impl Default for ToolCache {
     fn default() -> Self {
-        Self::new(1000, 300) // 1000 entries, 5 minutes default TTL
+        Self::new(DEFAULT_MAX_ENTRIES, 300) // 5 minutes default TTL
     }
 }
 
@@ -384,4 +465,102 @@ mod tests {
         // other_tool entry should remain
         assert!(cache.get(&other_call).is_some());
     }
+
+    #[test]
+    fn test_cache_key_ignores_parameter_order() {
+        let cache = ToolCache::new(10, 60);
+
+        let call = |keys: &[&str]| ToolCall {
+            tool_name: "web_search".to_string(),
+            parameters: keys
+                .iter()
+                .map(|k| (k.to_string(), serde_json::json!(format!("{}-value", k))))
+                .collect(),
+            call_id: None,
+        };
+        let result = ToolResult {
+            tool: "web_search".to_string(),
+            success: true,
+            output: serde_json::json!({"results": []}),
+            error: None,
+        };
+
+        let keys = ["query", "max_results", "search_type", "language"];
+        cache.put(&call(&keys), result, None);
+
+        // Freshly built maps with the same entries must hit regardless of insertion order
+        let mut reversed = keys;
+        reversed.reverse();
+        for _ in 0..50 {
+            assert!(cache.get(&call(&keys)).is_some());
+            assert!(cache.get(&call(&reversed)).is_some());
+        }
+        assert_eq!(cache.get_stats().cache_hits, 100);
+    }
+
+    #[test]
+    fn test_cache_scoped_by_session_context() {
+        let cache = ToolCache::new(10, 60);
+
+        let call = ToolCall {
+            tool_name: "web_search".to_string(),
+            parameters: HashMap::from([("query".to_string(), serde_json::json!("report"))]),
+            call_id: None,
+        };
+        let result = ToolResult {
+            tool: "web_search".to_string(),
+            success: true,
+            output: serde_json::json!({"results": []}),
+            error: None,
+        };
+        let session = |id: &str| prost_types::Struct {
+            fields: [(
+                "session_id".to_string(),
+                prost_types::Value {
+                    kind: Some(prost_types::value::Kind::StringValue(id.to_string())),
+                },
+            )]
+            .into(),
+        };
+
+        cache.put_with_context(&call, Some(&session("tenant-a")), result, None);
+
+        assert!(cache
+            .get_with_context(&call, Some(&session("tenant-a")))
+            .is_some());
+        assert!(cache
+            .get_with_context(&call, Some(&session("tenant-b")))
+            .is_none());
+        assert!(cache.get(&call).is_none());
+    }
+
+    #[test]
+    fn test_registry_tool_ttls() {
+        let cache = ToolCache::new(10, 3600).with_registry_ttls(&ToolRegistry::new());
+
+        // Tools with cache_ttl_ms in the registry are cacheable
+        assert_eq!(
+            cache.tool_ttl("calculator"),
+            Some(Duration::from_millis(3600000))
+        );
+        assert_eq!(
+            cache.tool_ttl("web_search"),
+            Some(Duration::from_millis(600000))
+        );
+
+        // Tools without a TTL (side-effecting code execution) are not
+        assert!(cache.tool_ttl("code_executor").is_none());
+        assert!(cache.tool_ttl("firecracker_executor").is_none());
+
+        cache.set_tool_ttl("code_executor", Duration::from_secs(5));
+        assert_eq!(
+            cache.tool_ttl("code_executor"),
+            Some(Duration::from_secs(5))
+        );
+
+        // The cache-wide default TTL caps per-tool TTLs
+        let capped = ToolCache::new(10, 60).with_registry_ttls(&ToolRegistry::new());
+        assert_eq!(capped.tool_ttl("calculator"), Some(Duration::from_secs(60)));
+        assert_eq!(capped.tool_ttl("web_search"), Some(Duration::from_secs(60)));
+    }
 }
@@ -21,6 +21,13 @@ pub struct ToolCapability {
     pub examples: Vec<ToolExample>,
     pub rate_limit: Option<RateLimit>,
     pub cache_ttl_ms: Option<u64>,
+    /// Whether calls may run concurrently with other tool calls (false for stateful tools)
+    #[serde(default = "default_parallel_safe")]
+    pub parallel_safe: bool,
+}
+
+fn default_parallel_safe() -> bool {
+    true
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -99,13 +106,29 @@ impl ToolRegistry {
             version: "1.0.0".to_string(),
             author: "shannon-core".to_string(),
             tags: vec!["math".to_string(), "calculation".to_string()],
-            examples: vec![ToolExample {
-                description: "Simple addition".to_string(),
-                input: serde_json::json!({"expression": "2 + 2"}),
-                output: serde_json::json!({"result": 4}),
-            }],
+            examples: vec![
+                ToolExample {
+                    description: "Simple addition".to_string(),
+                    input: serde_json::json!({"expression": "2 + 2"}),
+                    output: serde_json::json!({"result": 4.0, "expression": "2 + 2"}),
+                },
+                ToolExample {
+                    description: "Exponentiation with Python-style ** operator".to_string(),
+                    input: serde_json::json!({"expression": "2 ** 10"}),
+                    output: serde_json::json!({"result": 1024.0, "expression": "2 ** 10"}),
+                },
+                ToolExample {
+                    description: "Built-in functions and grouping".to_string(),
+                    input: serde_json::json!({"expression": "sqrt(16) * (3 + 1)"}),
+                    output: serde_json::json!({
+                        "result": 16.0,
+                        "expression": "sqrt(16) * (3 + 1)"
+                    }),
+                },
+            ],
             rate_limit: None,
             cache_ttl_ms: Some(3600000), // 1 hour
+            parallel_safe: true,
         };
 
         // Web search tool
@@ -129,13 +152,18 @@ impl ToolRegistry {
                 "required": ["query"]
             }),
             output_schema: serde_json::json!({
-                "type": "array",
-                "items": {
-                    "type": "object",
-                    "properties": {
-                        "title": {"type": "string"},
-                        "url": {"type": "string"},
-                        "snippet": {"type": "string"}
+                "type": "object",
+                "properties": {
+                    "results": {
+                        "type": "array",
+                        "items": {
+                            "type": "object",
+                            "properties": {
+                                "title": {"type": "string"},
+                                "url": {"type": "string"},
+                                "snippet": {"type": "string"}
+                            }
+                        }
                     }
                 }
             }),
@@ -149,12 +177,28 @@ impl ToolRegistry {
                 "web".to_string(),
                 "internet".to_string(),
             ],
-            examples: vec![],
+            examples: vec![ToolExample {
+                description: "Use a short keyword query, not a full question".to_string(),
+                input: serde_json::json!({
+                    "query": "rust tokio semaphore example",
+                    "max_results": 3
+                }),
+                output: serde_json::json!({
+                    "results": [
+                        {
+                            "title": "Semaphore in tokio::sync - Rust",
+                            "url": "https://docs.rs/tokio/latest/tokio/sync/struct.Semaphore.html",
+                            "snippet": "Counting semaphore performing asynchronous permit acquisition."
+                        }
+                    ]
+                }),
+            }],
             rate_limit: Some(RateLimit {
                 requests_per_minute: 60,
                 requests_per_hour: 1000,
             }),
             cache_ttl_ms: Some(600000), // 10 minutes
+            parallel_safe: true,
         };
 
         // Code executor tool (WASI)
@@ -200,6 +244,8 @@ impl ToolRegistry {
                 requests_per_hour: 100,
             }),
             cache_ttl_ms: None,
+            // Shares the session workspace with other code executions
+            parallel_safe: false,
         };
 
         // Firecracker executor tool (microVM)
@@ -246,6 +292,8 @@ impl ToolRegistry {
                 requests_per_hour: 100,
             }),
             cache_ttl_ms: None,
+            // Shares the session workspace with other code executions
+            parallel_safe: false,
         };
 
         self.register_tool(calculator);
@@ -271,6 +319,35 @@ impl ToolRegistry {
         tools.get(tool_id).cloned()
     }
 
+    /// Whether a tool may run concurrently with other tool calls.
+    /// Tools not in the registry (executed by the LLM service) may keep session
+    /// state (files, shell, browser), so they default to false.
+    pub fn can_execute_in_parallel(&self, tool_id: &str) -> bool {
+        let tools = self.tools.read().unwrap();
+        tools.get(tool_id).map(|t| t.parallel_safe).unwrap_or(false)
+    }
+
+    /// Group calls into execution batches, preserving call order.
+    /// Consecutive parallel-safe calls share a batch; any other call gets a batch
+    /// of its own, acting as a barrier between the calls before and after it.
+    pub fn execution_batches<T>(
+        &self,
+        calls: Vec<T>,
+        tool_name: impl Fn(&T) -> &str,
+    ) -> Vec<Vec<T>> {
+        let mut batches: Vec<Vec<T>> = Vec::new();
+        let mut open_batch = false;
+        for call in calls {
+            let parallel = self.can_execute_in_parallel(tool_name(&call));
+            match batches.last_mut() {
+                Some(batch) if parallel && open_batch => batch.push(call),
+                _ => batches.push(vec![call]),
+            }
+            open_batch = parallel;
+        }
+        batches
+    }
+
     /// List all available tools
     pub fn list_all_tools(&self) -> Vec<ToolCapability> {
         let tools = self.tools.read().unwrap();
@@ -2,11 +2,13 @@
 use crate::wasi_sandbox::WasiSandbox;
 use crate::{
     firecracker_client::{FirecrackerExecuteRequest, FirecrackerExecutorClient},
+    tool_cache::ToolCache,
     workspace::WorkspaceManager,
 };
 use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{debug, error, info, warn};
 
 use base64::Engine;
@@ -49,6 +51,63 @@ mod tests {
         assert!(res.success, "expected success: {:?}", res.error);
         assert_eq!(res.output, serde_json::Value::String(String::new()));
     }
+
+    #[tokio::test]
+    async fn test_calculator_examples_match_executor_output() {
+        let registry = crate::tool_registry::ToolRegistry::new();
+        let exec = ToolExecutor::new(None);
+
+        for example in registry.get_tool("calculator").unwrap().examples {
+            let call = ToolCall {
+                tool_name: "calculator".to_string(),
+                parameters: serde_json::from_value(example.input.clone()).unwrap(),
+                call_id: None,
+            };
+            let res = exec.execute_tool(&call, None).await.expect("tool result");
+            assert!(res.success, "{}: {:?}", example.description, res.error);
+            assert_eq!(res.output, example.output, "{}", example.description);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cached_tool_results_are_reused() {
+        let cache = Arc::new(
+            ToolCache::new(10, 60).with_registry_ttls(&crate::tool_registry::ToolRegistry::new()),
+        );
+        let exec = ToolExecutor::new(None).with_cache(Some(cache.clone()));
+
+        let call = ToolCall {
+            tool_name: "calculator".to_string(),
+            parameters: HashMap::from([("expression".to_string(), serde_json::json!("6 * 7"))]),
+            call_id: None,
+        };
+
+        let first = exec.execute_tool(&call, None).await.expect("tool result");
+        let second = exec.execute_tool(&call, None).await.expect("tool result");
+        assert!(first.success && second.success);
+        assert_eq!(first.output, second.output);
+
+        let stats = cache.get_stats();
+        assert_eq!(stats.cache_misses, 1);
+        assert_eq!(stats.cache_hits, 1);
+
+        // Another session's context never reuses the result
+        let other_session = prost_types::Struct {
+            fields: [(
+                "session_id".to_string(),
+                prost_types::Value {
+                    kind: Some(prost_types::value::Kind::StringValue("other".to_string())),
+                },
+            )]
+            .into(),
+        };
+        let third = exec
+            .execute_tool(&call, Some(&other_session))
+            .await
+            .expect("tool result");
+        assert!(third.success);
+        assert_eq!(cache.get_stats().cache_misses, 2);
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -66,6 +125,8 @@ pub struct ToolExecutor {
     /// When true, Firecracker errors fail fast without WASI fallback.
     /// Set via DISABLE_WASI_FALLBACK=1 env var (for EKS where Firecracker is required).
     disable_wasi_fallback: bool,
+    /// Optional shared result cache; only tools with a per-tool TTL are cached.
+    cache: Option<Arc<ToolCache>>,
 }
 
 impl ToolExecutor {
@@ -84,6 +145,7 @@ impl ToolExecutor {
             #[cfg(feature = "wasi")]
             wasi: None,
             disable_wasi_fallback: Self::should_disable_wasi_fallback(),
+            cache: None,
         }
     }
 
@@ -95,6 +157,7 @@ impl ToolExecutor {
                 .unwrap_or_else(|| "http://llm-service:8000".to_string()),
             wasi,
             disable_wasi_fallback: Self::should_disable_wasi_fallback(),
+            cache: None,
         }
     }
 
@@ -105,9 +168,16 @@ impl ToolExecutor {
                 .or_else(|| std::env::var("LLM_SERVICE_URL").ok())
                 .unwrap_or_else(|| "http://llm-service:8000".to_string()),
             disable_wasi_fallback: Self::should_disable_wasi_fallback(),
+            cache: None,
         }
     }
 
+    /// Attach a shared result cache to this executor (`None` disables caching)
+    pub fn with_cache(mut self, cache: Option<Arc<ToolCache>>) -> Self {
+        self.cache = cache;
+        self
+    }
+
     #[cfg(feature = "wasi")]
     pub fn set_wasi(&mut self, wasi: Option<WasiSandbox>) {
         self.wasi = wasi;
@@ -128,11 +198,41 @@ impl ToolExecutor {
         Ok(vec!["calculator".to_string()])
     }
 
-    /// Execute a tool via the LLM service
+    /// Execute a tool, serving cacheable tools from the attached cache when possible.
+    /// Cached results are scoped to `session_context`, which is forwarded to the LLM service.
     pub async fn execute_tool(
         &self,
         tool_call: &ToolCall,
         session_context: Option<&prost_types::Struct>,
+    ) -> Result<ToolResult> {
+        let cache = self
+            .cache
+            .as_ref()
+            .and_then(|c| c.tool_ttl(&tool_call.tool_name).map(|ttl| (c, ttl)));
+
+        if let Some((cache, _)) = cache {
+            if let Some(cached) = cache.get_with_context(tool_call, session_context) {
+                debug!("Serving tool '{}' from cache", tool_call.tool_name);
+                return Ok(cached);
+            }
+        }
+
+        let result = self
+            .execute_tool_uncached(tool_call, session_context)
+            .await?;
+
+        if let Some((cache, ttl)) = cache {
+            cache.put_with_context(tool_call, session_context, result.clone(), Some(ttl));
+        }
+
+        Ok(result)
+    }
+
+    /// Execute a tool via the LLM service
+    async fn execute_tool_uncached(
+        &self,
+        tool_call: &ToolCall,
+        session_context: Option<&prost_types::Struct>,
     ) -> Result<ToolResult> {
         info!(
             "Executing tool: {} with parameters: {:?}",
@@ -109,6 +109,7 @@ fn test_tool_registration() {
         examples: vec![],
         rate_limit: None,
         cache_ttl_ms: None,
+        parallel_safe: false,
     };
 
     registry.register_tool(custom_tool.clone());
@@ -117,6 +118,66 @@ fn test_tool_registration() {
     let retrieved = registry.get_tool("custom_tool");
     assert!(retrieved.is_some());
     assert_eq!(retrieved.unwrap().name, "Custom Tool");
+    assert!(!registry.can_execute_in_parallel("custom_tool"));
+}
+
+#[test]
+fn test_parallel_safety_defaults() {
+    let registry = ToolRegistry::new();
+
+    // Stateless tools may fan out
+    assert!(registry.can_execute_in_parallel("calculator"));
+    assert!(registry.can_execute_in_parallel("web_search"));
+
+    // Code execution shares the session workspace
+    assert!(!registry.can_execute_in_parallel("code_executor"));
+    assert!(!registry.can_execute_in_parallel("firecracker_executor"));
+
+    // Unregistered tools (executed by the LLM service) may keep session state
+    for tool in ["file_write", "file_read", "bash", "browser", "unknown_tool"] {
+        assert!(!registry.can_execute_in_parallel(tool), "{tool}");
+    }
+}
+
+#[test]
+fn test_execution_batches_preserve_order() {
+    let registry = ToolRegistry::new();
+    let batches = |calls: &[&'static str]| registry.execution_batches(calls.to_vec(), |c| *c);
+
+    // Parallel-safe calls fan out together
+    assert_eq!(
+        batches(&["calculator", "web_search", "calculator"]),
+        vec![vec!["calculator", "web_search", "calculator"]]
+    );
+
+    // A stateful call is a barrier: earlier calls finish before it, later ones start after it
+    assert_eq!(
+        batches(&[
+            "web_search",
+            "calculator",
+            "file_write",
+            "web_search",
+            "calculator"
+        ]),
+        vec![
+            vec!["web_search", "calculator"],
+            vec!["file_write"],
+            vec!["web_search", "calculator"],
+        ]
+    );
+
+    // Stateful calls never share a batch and keep their relative order
+    assert_eq!(
+        batches(&["file_write", "file_read", "bash", "file_list"]),
+        vec![
+            vec!["file_write"],
+            vec!["file_read"],
+            vec!["bash"],
+            vec!["file_list"],
+        ]
+    );
+
+    assert!(batches(&[]).is_empty());
 }
 
 #[test]
@@ -135,3 +196,39 @@ fn test_max_results_limit() {
     let results = registry.discover_tools(request);
     assert_eq!(results.len(), 2, "Should limit results to 2");
 }
+
+#[test]
+fn test_tool_examples_match_input_schema() {
+    let registry = ToolRegistry::new();
+
+    for id in ["calculator", "web_search"] {
+        let tool = registry.get_tool(id).unwrap();
+        assert!(!tool.examples.is_empty(), "{} should have examples", id);
+
+        // Every example must supply the schema's required arguments
+        let required = tool.input_schema["required"].as_array().unwrap();
+        for example in &tool.examples {
+            for field in required {
+                let field = field.as_str().unwrap();
+                assert!(
+                    example.input.get(field).is_some(),
+                    "{} example '{}' is missing required field '{}'",
+                    id,
+                    example.description,
+                    field
+                );
+            }
+
+            // Proto ToolExample input/output are google.protobuf.Struct, so both must be objects
+            assert!(
+                example.input.is_object() && example.output.is_object(),
+                "{} example '{}' must use JSON objects for input and output",
+                id,
+                example.description
+            );
+        }
+
+        // Same constraint for the schemas carried in proto ToolCapability
+        assert!(tool.input_schema.is_object() && tool.output_schema.is_object());
+    }
+}